@@ -0,0 +1,54 @@
+use zed_extension_api::{self as zed, LanguageServerId, Result};
+
+use crate::language_servers::{LanguageServer, RuboCop, RubyLsp, Solargraph, Sorbet};
+
+/// Each Ruby language server is implemented as its own `LanguageServer`
+/// and instantiated lazily the first time Zed asks for it. Adding a new
+/// server is a matter of implementing the trait in `language_servers/` and
+/// registering its id here; users pick which ones run via the
+/// `language_servers` setting (e.g. `["ruby-lsp", "!solargraph"]`).
+struct RubyExtension {
+    ruby_lsp: Option<RubyLsp>,
+    solargraph: Option<Solargraph>,
+    sorbet: Option<Sorbet>,
+    rubocop: Option<RuboCop>,
+}
+
+impl zed::Extension for RubyExtension {
+    fn new() -> Self {
+        Self {
+            ruby_lsp: None,
+            solargraph: None,
+            sorbet: None,
+            rubocop: None,
+        }
+    }
+
+    fn language_server_command(
+        &mut self,
+        language_server_id: &LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<zed::Command> {
+        match language_server_id.as_ref() {
+            RubyLsp::SERVER_ID => self
+                .ruby_lsp
+                .get_or_insert_with(RubyLsp::new)
+                .language_server_command(language_server_id, worktree),
+            Solargraph::SERVER_ID => self
+                .solargraph
+                .get_or_insert_with(Solargraph::new)
+                .language_server_command(language_server_id, worktree),
+            Sorbet::SERVER_ID => self
+                .sorbet
+                .get_or_insert_with(Sorbet::new)
+                .language_server_command(language_server_id, worktree),
+            RuboCop::SERVER_ID => self
+                .rubocop
+                .get_or_insert_with(RuboCop::new)
+                .language_server_command(language_server_id, worktree),
+            id => Err(format!("Unknown language server: {id}")),
+        }
+    }
+}
+
+zed::register_extension!(RubyExtension);
@@ -1,5 +1,6 @@
 use zed_extension_api::{
-    self as zed, process::Command, settings::LspSettings, LanguageServerId, Result,
+    self as zed, process::Command, settings::LspSettings, DownloadedFileType,
+    GithubReleaseOptions, LanguageServerId, LanguageServerInstallationStatus, Result,
 };
 
 #[derive(Clone, Debug)]
@@ -9,11 +10,38 @@ pub struct LanguageServerBinary {
     pub env: Option<Vec<(String, String)>>,
 }
 
+/// Whether a previously installed binary can be reused as-is: it must still
+/// exist on disk, and if a specific version was requested it must be the
+/// version that was actually cached (otherwise a `version` setting change
+/// would silently keep running the old install).
+fn cached_binary_is_valid(
+    cached_version: Option<&str>,
+    requested_version: Option<&str>,
+    binary_exists: bool,
+) -> bool {
+    binary_exists && cached_version == requested_version
+}
+
 pub trait LanguageServer {
     const SERVER_ID: &str;
     const EXECUTABLE_NAME: &str;
     const GEM_NAME: &str;
 
+    /// The path of the binary installed by a previous call to
+    /// `language_server_binary`, if any. Implementors should back this with a
+    /// `cached_binary_path: Option<String>` field.
+    fn cached_binary_path(&self) -> Option<String>;
+
+    fn set_cached_binary_path(&mut self, path: Option<String>);
+
+    /// The version recorded for the cached binary: the `version` setting it was
+    /// gem-installed with (`None` when no version was pinned), or the release
+    /// tag for a `github_release` download. Backed by a
+    /// `cached_version: Option<String>` field.
+    fn cached_version(&self) -> Option<String>;
+
+    fn set_cached_version(&mut self, version: Option<String>);
+
     fn default_use_bundler() -> bool {
         true // Default for most LSPs except Ruby LSP
     }
@@ -22,6 +50,181 @@ pub trait LanguageServer {
         Vec::new()
     }
 
+    /// The `install_method` to use when the LSP settings don't specify one.
+    /// Servers that ship a native binary (e.g. Sorbet's `srb`) can override
+    /// this to prefer `"github_release"`, but should still defer to an
+    /// existing bundler-managed install (see `bundler_manages_gem`) so that a
+    /// project that already pins the gem in its `Gemfile.lock` keeps getting
+    /// that resolved version instead of silently switching to the latest
+    /// release binary.
+    fn default_install_method(_worktree: &zed::Worktree) -> &'static str {
+        "gem"
+    }
+
+    /// Whether `bundle` is available in the worktree and its `Gemfile.lock`
+    /// already resolves `GEM_NAME`, meaning the bundler path should be
+    /// preferred over any other install method.
+    fn bundler_manages_gem(worktree: &zed::Worktree) -> bool {
+        worktree.which("bundle").is_some()
+            && worktree
+                .read_text_file("Gemfile.lock")
+                .is_ok_and(|contents| {
+                    contents
+                        .lines()
+                        .any(|line| {
+                            line.trim_start().split([' ', '!']).next() == Some(Self::GEM_NAME)
+                        })
+                })
+    }
+
+    /// The GitHub repo (`"owner/repo"`) to fetch prebuilt binaries from when
+    /// `install_method` is set to `"github_release"`. Servers that ship a
+    /// native binary (e.g. Sorbet's `srb`) should override this.
+    fn github_release_repo() -> Option<&'static str> {
+        None
+    }
+
+    /// The name of the release asset to download for the given platform, e.g.
+    /// `sorbet-static-x86_64-linux.tar.gz`.
+    fn github_release_asset_name(_os: zed::Os, _arch: zed::Architecture) -> String {
+        Self::EXECUTABLE_NAME.to_string()
+    }
+
+    fn download_release(
+        &mut self,
+        language_server_id: &LanguageServerId,
+    ) -> Result<LanguageServerBinary> {
+        let repo = Self::github_release_repo().ok_or_else(|| {
+            format!(
+                "{} does not support installing via github_release",
+                Self::SERVER_ID
+            )
+        })?;
+
+        zed::set_language_server_installation_status(
+            language_server_id,
+            &LanguageServerInstallationStatus::CheckingForUpdate,
+        );
+
+        let release = zed::latest_github_release(
+            repo,
+            GithubReleaseOptions {
+                require_assets: true,
+                pre_release: false,
+            },
+        )?;
+
+        let version_dir = format!("{}-{}", Self::EXECUTABLE_NAME, release.version);
+        let binary_path = format!("{}/{}", version_dir, Self::EXECUTABLE_NAME);
+
+        let binary_exists = std::fs::metadata(&binary_path).is_ok_and(|metadata| metadata.is_file());
+        if cached_binary_is_valid(
+            self.cached_version().as_deref(),
+            Some(release.version.as_str()),
+            binary_exists,
+        ) {
+            zed::set_language_server_installation_status(
+                language_server_id,
+                &LanguageServerInstallationStatus::None,
+            );
+
+            return Ok(LanguageServerBinary {
+                path: binary_path,
+                args: Some(Self::get_executable_args()),
+                env: Default::default(),
+            });
+        }
+
+        let (os, arch) = zed::current_platform();
+        let asset_name = Self::github_release_asset_name(os, arch);
+        let asset = release
+            .assets
+            .iter()
+            .find(|asset| asset.name == asset_name)
+            .ok_or_else(|| format!("No release asset found matching '{asset_name}'"))?;
+
+        zed::set_language_server_installation_status(
+            language_server_id,
+            &LanguageServerInstallationStatus::Downloading,
+        );
+
+        let file_type = if asset.name.ends_with(".zip") {
+            DownloadedFileType::Zip
+        } else if asset.name.ends_with(".tar.gz") || asset.name.ends_with(".tgz") {
+            DownloadedFileType::GzipTar
+        } else if asset.name.ends_with(".gz") {
+            DownloadedFileType::Gzip
+        } else if asset.name.ends_with(".xz") {
+            // `zed_extension_api` has no `DownloadedFileType` variant for xz-compressed
+            // archives, so there is nothing we can hand to `download_file` for these.
+            return Err(format!(
+                "Unsupported archive format for release asset '{asset_name}': .xz is not supported"
+            ));
+        } else {
+            DownloadedFileType::Uncompressed
+        };
+
+        // Archive types extract into a directory; `Gzip`/`Uncompressed` assets
+        // decompress straight to the destination path as a single file.
+        let download_destination = match file_type {
+            DownloadedFileType::Zip | DownloadedFileType::GzipTar => &version_dir,
+            DownloadedFileType::Gzip | DownloadedFileType::Uncompressed => &binary_path,
+        };
+
+        zed::download_file(&asset.download_url, download_destination, file_type)
+            .map_err(|e| format!("Failed to download {asset_name}: {e}"))?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&binary_path, std::fs::Permissions::from_mode(0o755))
+                .map_err(|e| format!("Failed to set permissions on {binary_path}: {e}"))?;
+        }
+
+        self.set_cached_binary_path(Some(binary_path.clone()));
+        self.set_cached_version(Some(release.version));
+        zed::set_language_server_installation_status(
+            language_server_id,
+            &LanguageServerInstallationStatus::None,
+        );
+
+        Ok(LanguageServerBinary {
+            path: binary_path,
+            args: Some(Self::get_executable_args()),
+            env: Default::default(),
+        })
+    }
+
+    /// The version of `GEM_NAME` that `bundle` has resolved in the
+    /// worktree's `Gemfile.lock`, if it can be determined.
+    fn bundled_gem_version(bundle_path: &str) -> Option<String> {
+        let output = Command::new(bundle_path)
+            .arg("exec")
+            .arg("gem")
+            .arg("list")
+            .arg("--local")
+            .arg(format!("^{}$", Self::GEM_NAME))
+            .output()
+            .ok()?;
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| {
+                let (name, rest) = line.split_once(' ')?;
+                if name != Self::GEM_NAME {
+                    return None;
+                }
+
+                // `gem list` reports every locally installed version on one
+                // line, e.g. "sorbet (1.60.0, 1.59.0)" — the first one is the
+                // version that gets activated.
+                let versions = rest.trim_matches(|c| c == '(' || c == ')');
+                let activated_version = versions.split(", ").next()?;
+
+                Some(activated_version.to_string())
+            })
+    }
+
     fn language_server_command(
         &mut self,
         language_server_id: &LanguageServerId,
@@ -37,7 +240,7 @@ pub trait LanguageServer {
     }
 
     fn language_server_binary(
-        &self,
+        &mut self,
         language_server_id: &LanguageServerId,
         worktree: &zed::Worktree,
     ) -> Result<LanguageServerBinary> {
@@ -53,54 +256,141 @@ pub trait LanguageServer {
             }
         }
 
+        let install_method = lsp_settings
+            .settings
+            .as_ref()
+            .and_then(|settings| settings["install_method"].as_str())
+            .unwrap_or(Self::default_install_method(worktree))
+            .to_string();
+
+        if install_method == "github_release" {
+            return self.download_release(language_server_id);
+        }
+
         let use_bundler = lsp_settings
             .settings
             .as_ref()
             .and_then(|settings| settings["use_bundler"].as_bool())
             .unwrap_or(Self::default_use_bundler());
 
+        let use_path = lsp_settings
+            .settings
+            .as_ref()
+            .and_then(|settings| settings["use_path"].as_bool())
+            .unwrap_or(true);
+
+        let version = lsp_settings
+            .settings
+            .as_ref()
+            .and_then(|settings| settings["version"].as_str())
+            .map(|version| version.to_string());
+
         if use_bundler {
-            worktree
+            let bundle_path = worktree
                 .which("bundle")
-                .map(|path| LanguageServerBinary {
-                    path,
-                    args: Some(
-                        [
-                            vec!["exec".to_string(), Self::EXECUTABLE_NAME.to_string()],
-                            Self::get_executable_args(),
-                        ]
-                        .concat(),
-                    ),
-                    env: Default::default(),
-                })
-                .ok_or_else(|| "Unable to find the 'bundle' command.".into())
+                .ok_or_else(|| "Unable to find the 'bundle' command.".to_string())?;
+
+            if let Some(expected_version) = &version {
+                if let Some(bundled_version) = Self::bundled_gem_version(&bundle_path) {
+                    if &bundled_version != expected_version {
+                        eprintln!(
+                            "{}: configured version \"{expected_version}\" does not match the Gemfile.lock-resolved {} version \"{bundled_version}\"; using the bundled version.",
+                            Self::SERVER_ID,
+                            Self::GEM_NAME
+                        );
+                    }
+                }
+            }
+
+            Ok(LanguageServerBinary {
+                path: bundle_path,
+                args: Some(
+                    [
+                        vec!["exec".to_string(), Self::EXECUTABLE_NAME.to_string()],
+                        Self::get_executable_args(),
+                    ]
+                    .concat(),
+                ),
+                env: Default::default(),
+            })
+        } else if let Some(path) = use_path.then(|| worktree.which(Self::EXECUTABLE_NAME)).flatten() {
+            Ok(LanguageServerBinary {
+                path,
+                args: Some(Self::get_executable_args()),
+                env: Default::default(),
+            })
         } else {
+            zed::set_language_server_installation_status(
+                language_server_id,
+                &LanguageServerInstallationStatus::CheckingForUpdate,
+            );
+
             let current_directory = std::env::current_dir()
                 .map_err(|e| format!("Failed to get current directory: {}", e))?
                 .to_string_lossy()
                 .to_string();
 
-            let output = Command::new("gem")
+            let gem_env = Some(vec![(
+                "GEM_PATH".to_string(),
+                format!("{gem_path}:$GEM_PATH", gem_path = current_directory),
+            )]);
+
+            if let Some(path) = self.cached_binary_path() {
+                let binary_exists = std::fs::metadata(&path).is_ok_and(|metadata| metadata.is_file());
+                if cached_binary_is_valid(
+                    self.cached_version().as_deref(),
+                    version.as_deref(),
+                    binary_exists,
+                ) {
+                    zed::set_language_server_installation_status(
+                        language_server_id,
+                        &LanguageServerInstallationStatus::None,
+                    );
+
+                    return Ok(LanguageServerBinary {
+                        path,
+                        args: Some(Self::get_executable_args()),
+                        env: gem_env,
+                    });
+                }
+            }
+
+            zed::set_language_server_installation_status(
+                language_server_id,
+                &LanguageServerInstallationStatus::Downloading,
+            );
+
+            let mut command = Command::new("gem");
+            command
                 .env("GEM_HOME", current_directory.clone())
                 .arg("install")
                 .arg("--no-user-install")
                 .arg("--no-format-executable")
-                .arg("--no-document")
-                .arg(Self::GEM_NAME)
-                .output()?;
+                .arg("--no-document");
+
+            if let Some(version) = &version {
+                command.arg("--version").arg(version);
+            }
+
+            let output = command.arg(Self::GEM_NAME).output()?;
 
             let stderr_output = String::from_utf8_lossy(&output.stderr).to_string();
 
             match output.status {
                 Some(status) => {
                     if status == 0 {
+                        let path = format!("{}/bin/{}", current_directory, Self::EXECUTABLE_NAME);
+                        self.set_cached_binary_path(Some(path.clone()));
+                        self.set_cached_version(version);
+                        zed::set_language_server_installation_status(
+                            language_server_id,
+                            &LanguageServerInstallationStatus::None,
+                        );
+
                         Ok(LanguageServerBinary {
-                            path: format!("{}/bin/{}", current_directory, Self::EXECUTABLE_NAME),
+                            path,
                             args: Some(Self::get_executable_args()),
-                            env: Some(vec![(
-                                "GEM_PATH".to_string(),
-                                format!("{gem_path}:$GEM_PATH", gem_path = current_directory),
-                            )]),
+                            env: gem_env,
                         })
                     } else {
                         Err(format!(
@@ -122,12 +412,31 @@ pub trait LanguageServer {
 mod tests {
     use super::*;
 
-    struct TestServer {}
+    struct TestServer {
+        cached_binary_path: Option<String>,
+        cached_version: Option<String>,
+    }
     impl LanguageServer for TestServer {
         const SERVER_ID: &'static str = "test-server";
         const EXECUTABLE_NAME: &'static str = "test-exe";
         const GEM_NAME: &'static str = "test";
 
+        fn cached_binary_path(&self) -> Option<String> {
+            self.cached_binary_path.clone()
+        }
+
+        fn set_cached_binary_path(&mut self, path: Option<String>) {
+            self.cached_binary_path = path;
+        }
+
+        fn cached_version(&self) -> Option<String> {
+            self.cached_version.clone()
+        }
+
+        fn set_cached_version(&mut self, version: Option<String>) {
+            self.cached_version = version;
+        }
+
         fn get_executable_args() -> Vec<String> {
             vec!["--test-arg".into()]
         }
@@ -142,4 +451,29 @@ mod tests {
     fn test_default_executable_args() {
         assert!(TestServer::get_executable_args() == vec!["--test-arg"]);
     }
+
+    #[test]
+    fn test_cached_binary_is_valid_when_versions_match_and_binary_exists() {
+        assert!(cached_binary_is_valid(Some("1.2.3"), Some("1.2.3"), true));
+    }
+
+    #[test]
+    fn test_cached_binary_is_valid_with_no_version_pinned() {
+        assert!(cached_binary_is_valid(None, None, true));
+    }
+
+    #[test]
+    fn test_cached_binary_is_invalid_when_binary_is_missing() {
+        assert!(!cached_binary_is_valid(Some("1.2.3"), Some("1.2.3"), false));
+    }
+
+    #[test]
+    fn test_cached_binary_is_invalid_when_pinned_version_changes() {
+        assert!(!cached_binary_is_valid(Some("1.2.3"), Some("1.3.0"), true));
+    }
+
+    #[test]
+    fn test_cached_binary_is_invalid_when_version_pin_is_newly_added() {
+        assert!(!cached_binary_is_valid(None, Some("1.2.3"), true));
+    }
 }
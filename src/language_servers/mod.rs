@@ -0,0 +1,11 @@
+mod language_server;
+mod rubocop;
+mod ruby_lsp;
+mod solargraph;
+mod sorbet;
+
+pub use language_server::{LanguageServer, LanguageServerBinary};
+pub use rubocop::RuboCop;
+pub use ruby_lsp::RubyLsp;
+pub use solargraph::Solargraph;
+pub use sorbet::Sorbet;
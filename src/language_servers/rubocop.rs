@@ -0,0 +1,39 @@
+use super::language_server::LanguageServer;
+
+#[derive(Default)]
+pub struct RuboCop {
+    cached_binary_path: Option<String>,
+    cached_version: Option<String>,
+}
+
+impl RuboCop {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl LanguageServer for RuboCop {
+    const SERVER_ID: &str = "rubocop";
+    const EXECUTABLE_NAME: &str = "rubocop";
+    const GEM_NAME: &str = "rubocop";
+
+    fn cached_binary_path(&self) -> Option<String> {
+        self.cached_binary_path.clone()
+    }
+
+    fn set_cached_binary_path(&mut self, path: Option<String>) {
+        self.cached_binary_path = path;
+    }
+
+    fn cached_version(&self) -> Option<String> {
+        self.cached_version.clone()
+    }
+
+    fn set_cached_version(&mut self, version: Option<String>) {
+        self.cached_version = version;
+    }
+
+    fn get_executable_args() -> Vec<String> {
+        vec!["--lsp".to_string()]
+    }
+}
@@ -0,0 +1,39 @@
+use super::language_server::LanguageServer;
+
+#[derive(Default)]
+pub struct RubyLsp {
+    cached_binary_path: Option<String>,
+    cached_version: Option<String>,
+}
+
+impl RubyLsp {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl LanguageServer for RubyLsp {
+    const SERVER_ID: &str = "ruby-lsp";
+    const EXECUTABLE_NAME: &str = "ruby-lsp";
+    const GEM_NAME: &str = "ruby-lsp";
+
+    fn cached_binary_path(&self) -> Option<String> {
+        self.cached_binary_path.clone()
+    }
+
+    fn set_cached_binary_path(&mut self, path: Option<String>) {
+        self.cached_binary_path = path;
+    }
+
+    fn cached_version(&self) -> Option<String> {
+        self.cached_version.clone()
+    }
+
+    fn set_cached_version(&mut self, version: Option<String>) {
+        self.cached_version = version;
+    }
+
+    fn default_use_bundler() -> bool {
+        false // Ruby LSP is commonly installed standalone, not via the project's Gemfile.
+    }
+}
@@ -0,0 +1,39 @@
+use super::language_server::LanguageServer;
+
+#[derive(Default)]
+pub struct Solargraph {
+    cached_binary_path: Option<String>,
+    cached_version: Option<String>,
+}
+
+impl Solargraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl LanguageServer for Solargraph {
+    const SERVER_ID: &str = "solargraph";
+    const EXECUTABLE_NAME: &str = "solargraph";
+    const GEM_NAME: &str = "solargraph";
+
+    fn cached_binary_path(&self) -> Option<String> {
+        self.cached_binary_path.clone()
+    }
+
+    fn set_cached_binary_path(&mut self, path: Option<String>) {
+        self.cached_binary_path = path;
+    }
+
+    fn cached_version(&self) -> Option<String> {
+        self.cached_version.clone()
+    }
+
+    fn set_cached_version(&mut self, version: Option<String>) {
+        self.cached_version = version;
+    }
+
+    fn get_executable_args() -> Vec<String> {
+        vec!["stdio".to_string()]
+    }
+}
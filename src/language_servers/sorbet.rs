@@ -0,0 +1,87 @@
+use super::language_server::LanguageServer;
+use zed_extension_api::{self as zed};
+
+#[derive(Default)]
+pub struct Sorbet {
+    cached_binary_path: Option<String>,
+    cached_version: Option<String>,
+}
+
+impl Sorbet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl LanguageServer for Sorbet {
+    const SERVER_ID: &str = "sorbet";
+    const EXECUTABLE_NAME: &str = "srb";
+    const GEM_NAME: &str = "sorbet";
+
+    fn cached_binary_path(&self) -> Option<String> {
+        self.cached_binary_path.clone()
+    }
+
+    fn set_cached_binary_path(&mut self, path: Option<String>) {
+        self.cached_binary_path = path;
+    }
+
+    fn cached_version(&self) -> Option<String> {
+        self.cached_version.clone()
+    }
+
+    fn set_cached_version(&mut self, version: Option<String>) {
+        self.cached_version = version;
+    }
+
+    fn get_executable_args() -> Vec<String> {
+        vec!["tc".to_string(), "--lsp".to_string()]
+    }
+
+    fn default_install_method(worktree: &zed::Worktree) -> &'static str {
+        if Self::bundler_manages_gem(worktree) {
+            "gem" // Keep using the Gemfile.lock-resolved sorbet install.
+        } else {
+            "github_release"
+        }
+    }
+
+    fn github_release_repo() -> Option<&'static str> {
+        Some("sorbet/sorbet")
+    }
+
+    fn github_release_asset_name(os: zed::Os, arch: zed::Architecture) -> String {
+        let os_name = match os {
+            zed::Os::Mac => "darwin",
+            zed::Os::Linux => "linux",
+            zed::Os::Windows => "windows",
+        };
+        let arch_name = match arch {
+            zed::Architecture::Aarch64 => "arm64",
+            zed::Architecture::X86 | zed::Architecture::X8664 => "x86_64",
+        };
+
+        format!("sorbet-static-{arch_name}-{os_name}.tar.gz")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_github_release_asset_name() {
+        let cases = [
+            (zed::Os::Mac, zed::Architecture::Aarch64, "sorbet-static-arm64-darwin.tar.gz"),
+            (zed::Os::Mac, zed::Architecture::X8664, "sorbet-static-x86_64-darwin.tar.gz"),
+            (zed::Os::Linux, zed::Architecture::Aarch64, "sorbet-static-arm64-linux.tar.gz"),
+            (zed::Os::Linux, zed::Architecture::X86, "sorbet-static-x86_64-linux.tar.gz"),
+            (zed::Os::Linux, zed::Architecture::X8664, "sorbet-static-x86_64-linux.tar.gz"),
+            (zed::Os::Windows, zed::Architecture::X8664, "sorbet-static-x86_64-windows.tar.gz"),
+        ];
+
+        for (os, arch, expected) in cases {
+            assert_eq!(Sorbet::github_release_asset_name(os, arch), expected);
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod extension;
+mod language_servers;